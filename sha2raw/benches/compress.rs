@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use rand::{thread_rng, Rng};
+use sha2raw::Implementation;
+
+fn compress256_benchmark(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let data: Vec<u8> = (0..64 * 32).map(|_| rng.gen()).collect();
+    let blocks = data.chunks(32).collect::<Vec<_>>();
+
+    #[allow(unused_mut)]
+    let mut impls = vec![("portable", Implementation::portable())];
+    #[cfg(feature = "asm")]
+    impls.extend(Implementation::asm_if_supported().map(|imp| ("asm", imp)));
+    #[cfg(target_arch = "x86_64")]
+    impls.extend(Implementation::sha_if_supported().map(|imp| ("sha-ni", imp)));
+
+    let mut group = c.benchmark_group("sha256-compress256");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+    for (name, imp) in impls {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut state = [0u32; 8];
+                imp.compress256(&mut state, black_box(&blocks));
+                black_box(state)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, compress256_benchmark);
+criterion_main!(benches);
@@ -5,6 +5,12 @@
 #![deny(clippy::all, clippy::perf, clippy::correctness)]
 #![allow(clippy::unreadable_literal)]
 
+#[cfg(all(feature = "sha-ni", feature = "sha-sw"))]
+compile_error!("features `sha-ni` and `sha-sw` are mutually exclusive");
+
+#[cfg(all(feature = "sha-ni", not(target_arch = "x86_64")))]
+compile_error!("feature `sha-ni` is only supported on x86_64");
+
 pub use digest::Digest;
 
 mod consts;
@@ -14,4 +20,8 @@ mod sha256;
 mod sha256_intrinsics;
 mod sha256_utils;
 
-pub use sha256::Sha256;
+pub use sha256::{measure_hash_rate, Sha256};
+
+// Only exposed so the benchmarks can compare the individual backends.
+#[doc(hidden)]
+pub use platform::Implementation;
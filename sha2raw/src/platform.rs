@@ -16,9 +16,18 @@ enum Platform {
 pub struct Implementation(Platform);
 
 impl Implementation {
+    #[allow(unreachable_code)]
     pub fn detect() -> Self {
+        // The `sha-ni` feature skips detection entirely, failing loudly instead of silently
+        // falling back to a slower implementation.
+        #[cfg(all(feature = "sha-ni", target_arch = "x86_64"))]
+        {
+            return Self::sha_if_supported()
+                .expect("feature `sha-ni` is enabled, but this CPU does not support sha-ni");
+        }
+
         // Try the different implementations in order of how fast/modern they are.
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(all(target_arch = "x86_64", not(feature = "sha-sw")))]
         {
             if let Some(sha_impl) = Self::sha_if_supported() {
                 return sha_impl;
@@ -87,3 +96,45 @@ impl Implementation {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    use crate::consts::H256;
+
+    #[test]
+    fn test_implementations_agree() {
+        let rng = &mut XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // Compare every implementation this machine can run, regardless of which one
+        // `detect` would pick under the enabled features.
+        #[allow(unused_mut)]
+        let mut impls = vec![Implementation::detect()];
+        #[cfg(feature = "asm")]
+        impls.extend(Implementation::asm_if_supported());
+        #[cfg(target_arch = "x86_64")]
+        impls.extend(Implementation::sha_if_supported());
+
+        for k in 1..10 {
+            let mut input = vec![0u8; 64 * k];
+            rng.fill_bytes(&mut input);
+            let blocks = input.chunks(32).collect::<Vec<_>>();
+
+            let mut expected = H256;
+            Implementation::portable().compress256(&mut expected, &blocks);
+
+            for imp in &impls {
+                let mut state = H256;
+                imp.compress256(&mut state, &blocks);
+                assert_eq!(state, expected, "mismatch for {:?}", imp);
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use byteorder::{ByteOrder, BE};
 use lazy_static::lazy_static;
 
@@ -81,6 +83,30 @@ impl Sha256 {
 
 opaque_debug::implement!(Sha256);
 
+/// Hashes in a tight loop for roughly `duration` and returns the number of 64 byte digests
+/// per second this machine achieves with the detected backend.
+pub fn measure_hash_rate(duration: Duration) -> f64 {
+    // Check the clock only once per batch, to keep timing overhead out of the measurement.
+    const BATCH: u64 = 1024;
+
+    let mut block = [0u8; 64];
+    let mut hashes = 0u64;
+    let start = Instant::now();
+    loop {
+        for _ in 0..BATCH {
+            let digest = Sha256::digest(&[&block[..32], &block[32..]]);
+            block[..32].copy_from_slice(&digest);
+        }
+        hashes += BATCH;
+
+        if start.elapsed() >= duration {
+            break;
+        }
+    }
+
+    hashes as f64 / start.elapsed().as_secs_f64()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +126,12 @@ mod tests {
         fuzz(1_000);
     }
 
+    #[test]
+    fn test_measure_hash_rate() {
+        let rate = measure_hash_rate(Duration::from_millis(10));
+        assert!(rate > 0.0, "invalid hash rate: {}", rate);
+    }
+
     fn fuzz(n: usize) {
         let rng = &mut XorShiftRng::from_seed([
             0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,